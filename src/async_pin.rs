@@ -0,0 +1,133 @@
+//! Async, interrupt-driven debouncing built on `embedded-hal-async`'s edge-wait
+//! traits, gated behind the `async` feature.
+//!
+//! Instead of requiring `update()` to be polled on a steady ~1ms cadence, an
+//! [`AsyncDebouncedInputPin`] awaits the underlying pin's edge future, waits
+//! out a settle `Duration`, and re-reads the raw pin. If the pin bounced back
+//! before the settle time elapsed, it loops around and waits for the next
+//! edge. This gives embassy/RTIC-async users a zero-polling debounced button
+//! without wiring up a timer interrupt.
+//!
+//! `embedded-hal-async`'s `Wait` trait is built on top of the `embedded-hal`
+//! 1.0 `ErrorType`, so the wrapped pin is read through the 1.0
+//! `embedded_hal::digital::InputPin` trait here rather than the 0.2
+//! `v2::InputPin` trait used elsewhere in this crate.
+
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use eh1::digital::InputPin;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
+
+use crate::{ActiveHigh, ActiveLow};
+
+/// An async debounced input pin.
+///
+/// `T` is the underlying pin, which must support both synchronous reads and
+/// awaiting edges. `A` is the `ActiveHigh`/`ActiveLow` marker, and `D` is the
+/// async delay used to wait out the settle time.
+pub struct AsyncDebouncedInputPin<T, A, D> {
+    /// The wrapped pin.
+    pub pin: T,
+
+    /// The async delay used to wait out the settle time.
+    delay: D,
+
+    /// Whether the pin is active-high or active-low.
+    activeness: PhantomData<A>,
+
+    /// How long the pin has to stay settled after an edge before it's
+    /// considered active.
+    settle_time: Duration,
+}
+
+impl<T, A, D> AsyncDebouncedInputPin<T, A, D> {
+    /// Change the settle time that is awaited after an edge, before the raw
+    /// pin is re-read to confirm the transition.
+    pub fn set_settle_time(&mut self, settle_time: Duration) {
+        self.settle_time = settle_time;
+    }
+}
+
+impl<T: InputPin + Wait, D: DelayNs> AsyncDebouncedInputPin<T, ActiveHigh, D> {
+    /// Initializes a new `ActiveHigh` async debounced input pin.
+    pub fn active_high(pin: T, delay: D) -> Self {
+        Self {
+            pin,
+            delay,
+            activeness: PhantomData,
+            settle_time: Duration::from_millis(10),
+        }
+    }
+
+    /// Waits until the pin is debounced active.
+    ///
+    /// Awaits a rising edge, then the settle time, then re-reads the raw pin;
+    /// if it's no longer high this loops and waits for the next rising edge.
+    pub async fn wait_for_active(&mut self) -> Result<(), T::Error> {
+        loop {
+            self.pin.wait_for_rising_edge().await?;
+            self.delay.delay_ns(self.settle_time.as_nanos() as u32).await;
+            if self.pin.is_high()? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Waits until the pin is debounced inactive.
+    ///
+    /// Awaits a falling edge, then the settle time, then re-reads the raw
+    /// pin; if it's high again this loops and waits for the next falling
+    /// edge.
+    pub async fn wait_for_inactive(&mut self) -> Result<(), T::Error> {
+        loop {
+            self.pin.wait_for_falling_edge().await?;
+            self.delay.delay_ns(self.settle_time.as_nanos() as u32).await;
+            if self.pin.is_low()? {
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<T: InputPin + Wait, D: DelayNs> AsyncDebouncedInputPin<T, ActiveLow, D> {
+    /// Initializes a new `ActiveLow` async debounced input pin.
+    pub fn active_low(pin: T, delay: D) -> Self {
+        Self {
+            pin,
+            delay,
+            activeness: PhantomData,
+            settle_time: Duration::from_millis(10),
+        }
+    }
+
+    /// Waits until the pin is debounced active.
+    ///
+    /// Awaits a falling edge, then the settle time, then re-reads the raw
+    /// pin; if it's no longer low this loops and waits for the next falling
+    /// edge.
+    pub async fn wait_for_active(&mut self) -> Result<(), T::Error> {
+        loop {
+            self.pin.wait_for_falling_edge().await?;
+            self.delay.delay_ns(self.settle_time.as_nanos() as u32).await;
+            if self.pin.is_low()? {
+                return Ok(());
+            }
+        }
+    }
+
+    /// Waits until the pin is debounced inactive.
+    ///
+    /// Awaits a rising edge, then the settle time, then re-reads the raw
+    /// pin; if it's low again this loops and waits for the next rising edge.
+    pub async fn wait_for_inactive(&mut self) -> Result<(), T::Error> {
+        loop {
+            self.pin.wait_for_rising_edge().await?;
+            self.delay.delay_ns(self.settle_time.as_nanos() as u32).await;
+            if self.pin.is_high()? {
+                return Ok(());
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! `embedded-hal` 1.0 `InputPin` support, gated behind the `eh1` feature.
+//!
+//! These impls sit alongside the existing `embedded-hal` 0.2
+//! `v2::InputPin` impls on [`DebouncedInputPin`], so the crate can be
+//! consumed from both ecosystems while a HAL migrates from 0.2 to 1.0.
+
+use eh1::digital::{Error, ErrorKind, ErrorType, InputPin};
+
+use crate::DebouncedInputPin;
+use embedded_hal::digital::v2::InputPin as InputPinV2;
+
+/// Wraps a 0.2 `InputPin::Error` so it satisfies the 1.0 `Error` trait.
+///
+/// Most 0.2 HAL error types don't (and can't, being a foreign type)
+/// implement `embedded_hal::digital::Error` themselves, so this always maps
+/// them to `ErrorKind::Other` rather than requiring that impl from callers.
+#[derive(Debug)]
+pub struct WrappedError<E>(pub E);
+
+impl<E: core::fmt::Debug> Error for WrappedError<E> {
+    fn kind(&self) -> ErrorKind {
+        ErrorKind::Other
+    }
+}
+
+impl<T: InputPinV2, A> ErrorType for DebouncedInputPin<T, A>
+where
+    T::Error: core::fmt::Debug,
+{
+    type Error = WrappedError<T::Error>;
+}
+
+impl<T: InputPinV2, A> InputPin for DebouncedInputPin<T, A>
+where
+    T::Error: core::fmt::Debug,
+{
+    fn is_high(&mut self) -> Result<bool, Self::Error> {
+        Ok(self.state)
+    }
+
+    fn is_low(&mut self) -> Result<bool, Self::Error> {
+        Ok(!self.state)
+    }
+}
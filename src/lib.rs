@@ -48,6 +48,10 @@
 //!         DebounceState::Debouncing => continue,
 //!         // Pin is active and debounced.
 //!         DebounceState::Active => break,
+//!         // The debounced state just flipped this tick; treat the same as
+//!         // the steady-state variant it flipped into.
+//!         DebounceState::ActivatedEdge => break,
+//!         DebounceState::DeactivatedEdge => break,
 //!     }
 //!     // Also hardware specific
 //!     wait(1.ms());
@@ -96,11 +100,24 @@
 use core::marker::PhantomData;
 use embedded_hal::digital::v2::InputPin;
 
+/// Async, interrupt-driven debouncing on top of `embedded-hal-async`'s
+/// edge-wait traits. Requires the `async` feature.
+#[cfg(feature = "async")]
+pub mod async_pin;
+
+/// Time-driven debouncing, for callers that can't guarantee a steady ~1ms
+/// `update()` cadence.
+pub mod timed;
+
 /// Import the needed types and traits to use the `update()` method.
 pub mod prelude {
     pub use crate::Debounce;
     pub use crate::DebounceState;
     pub use crate::DebouncedInputPin;
+    pub use crate::timed::TimedDebouncedInputPin;
+
+    #[cfg(feature = "async")]
+    pub use crate::async_pin::AsyncDebouncedInputPin;
 }
 
 /// Unit struct for active-low pins.
@@ -111,6 +128,7 @@ pub struct ActiveHigh;
 
 /// The debounce state of the `update()` method
 #[derive(PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum DebounceState {
     /// The pin state is active, but not debounced
     Debouncing,
@@ -118,6 +136,10 @@ pub enum DebounceState {
     Reset,
     /// The pin state is high and is debounced
     Active,
+    /// The debounced state just flipped from inactive to active on this tick
+    ActivatedEdge,
+    /// The debounced state just flipped from active to inactive on this tick
+    DeactivatedEdge,
 }
 
 /// Debounce Trait which provides and update method which debounces the pin
@@ -216,16 +238,30 @@ impl<T: InputPin> Debounce for DebouncedInputPin<T, ActiveHigh> {
     /// Needs to be called every ~1ms.
     fn update(&mut self) -> Result<Self::State, Self::Error> {
         if self.pin.is_low()? {
+            let was_active = self.state;
             self.counter = 0;
             self.state = false;
-            Ok(DebounceState::Reset)
+            if was_active {
+                #[cfg(feature = "defmt")]
+                defmt::trace!("DebouncedInputPin: deactivated");
+                Ok(DebounceState::DeactivatedEdge)
+            } else {
+                Ok(DebounceState::Reset)
+            }
         } else if self.counter < self.max_counts {
             self.counter += 1;
             Ok(DebounceState::Debouncing)
         } else {
             // Max count is reached
+            let was_active = self.state;
             self.state = true;
-            Ok(DebounceState::Active)
+            if was_active {
+                Ok(DebounceState::Active)
+            } else {
+                #[cfg(feature = "defmt")]
+                defmt::trace!("DebouncedInputPin: activated");
+                Ok(DebounceState::ActivatedEdge)
+            }
         }
     }
 }
@@ -259,16 +295,30 @@ impl<T: InputPin> Debounce for DebouncedInputPin<T, ActiveLow> {
     /// Needs to be called every ~1ms.
     fn update(&mut self) -> Result<Self::State, Self::Error> {
         if self.pin.is_high()? {
+            let was_active = !self.state;
             self.counter = 0;
             self.state = true;
-            Ok(DebounceState::Reset)
+            if was_active {
+                #[cfg(feature = "defmt")]
+                defmt::trace!("DebouncedInputPin: deactivated");
+                Ok(DebounceState::DeactivatedEdge)
+            } else {
+                Ok(DebounceState::Reset)
+            }
         } else if self.counter < self.max_counts {
             self.counter += 1;
             Ok(DebounceState::Debouncing)
         } else {
             // Max count is reached
+            let was_active = !self.state;
             self.state = false;
-            Ok(DebounceState::Active)
+            if was_active {
+                Ok(DebounceState::Active)
+            } else {
+                #[cfg(feature = "defmt")]
+                defmt::trace!("DebouncedInputPin: activated");
+                Ok(DebounceState::ActivatedEdge)
+            }
         }
     }
 }
@@ -280,5 +330,9 @@ impl<T: InputPin> ActiveTrait for DebouncedInputPin<T, ActiveLow> {
     }
 }
 
+/// `embedded-hal` 1.0 `InputPin` support, gated behind the `eh1` feature.
+#[cfg(feature = "eh1")]
+pub mod eh1;
+
 #[cfg(test)]
 mod tests;
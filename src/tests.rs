@@ -1,3 +1,4 @@
+use crate::Debounce;
 use crate::DebounceState;
 use crate::DebouncedInputPin;
 use embedded_hal::digital::v2::InputPin;
@@ -30,6 +31,65 @@ mod mocks {
             Ok(!self.state)
         }
     }
+
+    /// A mock implementation of the `embedded-hal` 1.0 `InputPin` + `Wait` traits.
+    #[cfg(feature = "async")]
+    #[derive(Default)]
+    pub struct MockWaitInputPin {
+        /// The state of the pin.
+        pub state: bool,
+    }
+
+    #[cfg(feature = "async")]
+    impl eh1::digital::ErrorType for MockWaitInputPin {
+        type Error = core::convert::Infallible;
+    }
+
+    #[cfg(feature = "async")]
+    impl eh1::digital::InputPin for MockWaitInputPin {
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            Ok(self.state)
+        }
+
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            Ok(!self.state)
+        }
+    }
+
+    /// The mock pin is always already settled, so every edge wait resolves
+    /// immediately regardless of which edge is awaited.
+    #[cfg(feature = "async")]
+    impl embedded_hal_async::digital::Wait for MockWaitInputPin {
+        async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    /// A mock delay that resolves instantly.
+    #[cfg(feature = "async")]
+    #[derive(Default)]
+    pub struct MockDelay;
+
+    #[cfg(feature = "async")]
+    impl embedded_hal_async::delay::DelayNs for MockDelay {
+        async fn delay_ns(&mut self, _ns: u32) {}
+    }
 }
 
 /// Tests for `DebouncedInputPin<T, A>`.
@@ -105,7 +165,24 @@ mod input_pin {
             pin.pin.state = true;
             assert!(pin.update()? == DebounceState::Debouncing);
             pin.counter = 10;
+            assert!(pin.update()? == DebounceState::ActivatedEdge);
+            Ok(())
+        }
+
+        #[test]
+        fn it_returns_edge_variants_only_on_the_flipping_tick() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+
+            pin.pin.state = true;
+            pin.counter = 10;
+            assert!(pin.update()? == DebounceState::ActivatedEdge);
+            // Still active on the next tick, no repeated edge event.
             assert!(pin.update()? == DebounceState::Active);
+
+            pin.pin.state = false;
+            assert!(pin.update()? == DebounceState::DeactivatedEdge);
+            // Still inactive on the next tick, no repeated edge event.
+            assert!(pin.update()? == DebounceState::Reset);
             Ok(())
         }
     }
@@ -179,8 +256,302 @@ mod input_pin {
             pin.pin.state = false;
             assert!(pin.update()? == DebounceState::Debouncing);
             pin.counter = 10;
+            assert!(pin.update()? == DebounceState::ActivatedEdge);
+            Ok(())
+        }
+
+        #[test]
+        fn it_returns_edge_variants_only_on_the_flipping_tick() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+
+            pin.pin.state = false;
+            pin.counter = 10;
+            assert!(pin.update()? == DebounceState::ActivatedEdge);
+            // Still active on the next tick, no repeated edge event.
             assert!(pin.update()? == DebounceState::Active);
+
+            pin.pin.state = true;
+            assert!(pin.update()? == DebounceState::DeactivatedEdge);
+            // Still inactive on the next tick, no repeated edge event.
+            assert!(pin.update()? == DebounceState::Reset);
+            Ok(())
+        }
+    }
+}
+
+/// Tests for `TimedDebouncedInputPin<T, A>`.
+mod timed_input_pin {
+    use super::*;
+    use crate::timed::TimedDebouncedInputPin;
+    use core::time::Duration;
+
+    /// Tests for `TimedDebouncedInputPin<T, ActiveHigh>`.
+    mod active_high {
+        use super::*;
+        use crate::ActiveHigh; // Not importing `ActiveHigh` further up the chain to prevent mistakes.
+
+        /// Creates a `TimedDebouncedInputPin<MockInputPin, A>`.
+        pub fn create_pin() -> TimedDebouncedInputPin<MockInputPin, ActiveHigh> {
+            let pin = MockInputPin::default();
+            TimedDebouncedInputPin::active_high(pin)
+        }
+
+        #[test]
+        fn it_resets_on_low() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.pin.state = false;
+            assert!(pin.update(Duration::from_millis(1))? == DebounceState::Reset);
+            assert!(pin.is_low()?);
+            Ok(())
+        }
+
+        #[test]
+        fn it_debounces_until_the_debounce_time_has_elapsed() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.pin.state = true;
+            assert!(pin.update(Duration::from_millis(5))? == DebounceState::Debouncing);
+            assert!(pin.is_low()?);
             Ok(())
         }
+
+        #[test]
+        fn it_goes_active_once_the_debounce_time_has_elapsed() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.pin.state = true;
+            pin.update(Duration::from_millis(5))?;
+            assert!(pin.update(Duration::from_millis(10))? == DebounceState::Active);
+            assert!(pin.is_high()?);
+            Ok(())
+        }
+
+        #[test]
+        fn it_resets_the_elapsed_time_and_state_on_low() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.pin.state = true;
+            pin.update(Duration::from_millis(15))?;
+            assert!(pin.is_high()?);
+            pin.pin.state = false;
+            pin.update(Duration::from_millis(1))?;
+            assert!(pin.is_low()?);
+            Ok(())
+        }
+    }
+
+    /// Tests for `TimedDebouncedInputPin<T, ActiveLow>`.
+    mod active_low {
+        use super::*;
+        use crate::ActiveLow; // Not importing `ActiveLow` further up the chain to prevent mistakes.
+
+        /// Creates a `TimedDebouncedInputPin<MockInputPin, A>`.
+        pub fn create_pin() -> TimedDebouncedInputPin<MockInputPin, ActiveLow> {
+            let pin = MockInputPin::default();
+            TimedDebouncedInputPin::active_low(pin)
+        }
+
+        #[test]
+        fn it_resets_on_high() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.pin.state = true;
+            assert!(pin.update(Duration::from_millis(1))? == DebounceState::Reset);
+            assert!(pin.is_high()?);
+            Ok(())
+        }
+
+        #[test]
+        fn it_debounces_until_the_debounce_time_has_elapsed() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.pin.state = false;
+            assert!(pin.update(Duration::from_millis(5))? == DebounceState::Debouncing);
+            assert!(pin.is_high()?);
+            Ok(())
+        }
+
+        #[test]
+        fn it_goes_active_once_the_debounce_time_has_elapsed() -> Result<(), MockInputPinError> {
+            let mut pin = create_pin();
+            pin.pin.state = false;
+            pin.update(Duration::from_millis(5))?;
+            assert!(pin.update(Duration::from_millis(10))? == DebounceState::Active);
+            assert!(pin.is_low()?);
+            Ok(())
+        }
+    }
+}
+
+/// Tests for the `embedded-hal` 1.0 `InputPin` impl on `DebouncedInputPin<T, A>`.
+#[cfg(feature = "eh1")]
+mod eh1_input_pin {
+    use super::*;
+    use eh1::digital::InputPin as InputPinEh1;
+
+    #[test]
+    fn it_tracks_the_same_debounced_state_as_the_v0_2_impl() -> Result<(), MockInputPinError> {
+        let mut pin = DebouncedInputPin::active_high(MockInputPin::default());
+        pin.pin.state = true;
+        pin.counter = pin.max_counts;
+        pin.update()?;
+
+        assert_eq!(
+            InputPinEh1::is_high(&mut pin).unwrap(),
+            InputPin::is_high(&pin)?
+        );
+        assert_eq!(
+            InputPinEh1::is_low(&mut pin).unwrap(),
+            InputPin::is_low(&pin)?
+        );
+        assert!(InputPinEh1::is_high(&mut pin).unwrap());
+        Ok(())
+    }
+}
+
+/// Tests for `AsyncDebouncedInputPin<T, A, D>`.
+#[cfg(feature = "async")]
+mod async_input_pin {
+    use super::*;
+    use crate::async_pin::AsyncDebouncedInputPin;
+
+    /// A mock pin whose edge waits always resolve immediately, but whose raw
+    /// reads report a "bounce" (the opposite of `target`) for a configurable
+    /// number of reads before settling. This exercises the retry loop in
+    /// `wait_for_active`/`wait_for_inactive`, where a bounce during the
+    /// settle delay should send it back around to wait for the next edge.
+    pub struct BouncyWaitInputPin {
+        /// The level the pin reports once it has stopped bouncing.
+        pub target: bool,
+
+        /// How many more raw reads should report a bounce before `target`
+        /// is reported.
+        pub bounces: u32,
+    }
+
+    impl eh1::digital::ErrorType for BouncyWaitInputPin {
+        type Error = core::convert::Infallible;
+    }
+
+    impl eh1::digital::InputPin for BouncyWaitInputPin {
+        fn is_high(&mut self) -> Result<bool, Self::Error> {
+            if self.bounces > 0 {
+                self.bounces -= 1;
+                Ok(!self.target)
+            } else {
+                Ok(self.target)
+            }
+        }
+
+        fn is_low(&mut self) -> Result<bool, Self::Error> {
+            Ok(!self.is_high()?)
+        }
+    }
+
+    impl embedded_hal_async::digital::Wait for BouncyWaitInputPin {
+        async fn wait_for_high(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_low(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_rising_edge(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_falling_edge(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        async fn wait_for_any_edge(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    /// Tests for `AsyncDebouncedInputPin<T, ActiveHigh, D>`.
+    mod active_high {
+        use super::*;
+
+        #[test]
+        fn it_resolves_once_the_settled_pin_is_active() {
+            let pin = MockWaitInputPin { state: true };
+            let mut pin = AsyncDebouncedInputPin::active_high(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_active()).unwrap();
+        }
+
+        #[test]
+        fn it_retries_wait_for_active_if_the_pin_bounces_back_during_settle() {
+            let pin = BouncyWaitInputPin {
+                target: true,
+                bounces: 2,
+            };
+            let mut pin = AsyncDebouncedInputPin::active_high(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_active()).unwrap();
+            assert_eq!(pin.pin.bounces, 0);
+        }
+
+        #[test]
+        fn it_resolves_once_the_settled_pin_is_inactive() {
+            let pin = MockWaitInputPin { state: false };
+            let mut pin = AsyncDebouncedInputPin::active_high(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_inactive()).unwrap();
+        }
+
+        #[test]
+        fn it_retries_wait_for_inactive_if_the_pin_bounces_back_during_settle() {
+            let pin = BouncyWaitInputPin {
+                target: false,
+                bounces: 2,
+            };
+            let mut pin = AsyncDebouncedInputPin::active_high(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_inactive()).unwrap();
+            assert_eq!(pin.pin.bounces, 0);
+        }
+    }
+
+    /// Tests for `AsyncDebouncedInputPin<T, ActiveLow, D>`.
+    mod active_low {
+        use super::*;
+
+        #[test]
+        fn it_resolves_once_the_settled_pin_is_active() {
+            let pin = MockWaitInputPin { state: false };
+            let mut pin = AsyncDebouncedInputPin::active_low(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_active()).unwrap();
+        }
+
+        #[test]
+        fn it_retries_wait_for_active_if_the_pin_bounces_back_during_settle() {
+            let pin = BouncyWaitInputPin {
+                target: false,
+                bounces: 2,
+            };
+            let mut pin = AsyncDebouncedInputPin::active_low(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_active()).unwrap();
+            assert_eq!(pin.pin.bounces, 0);
+        }
+
+        #[test]
+        fn it_resolves_once_the_settled_pin_is_inactive() {
+            let pin = MockWaitInputPin { state: true };
+            let mut pin = AsyncDebouncedInputPin::active_low(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_inactive()).unwrap();
+        }
+
+        #[test]
+        fn it_retries_wait_for_inactive_if_the_pin_bounces_back_during_settle() {
+            let pin = BouncyWaitInputPin {
+                target: true,
+                bounces: 2,
+            };
+            let mut pin = AsyncDebouncedInputPin::active_low(pin, MockDelay);
+
+            futures::executor::block_on(pin.wait_for_inactive()).unwrap();
+            assert_eq!(pin.pin.bounces, 0);
+        }
     }
 }
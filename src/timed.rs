@@ -0,0 +1,133 @@
+//! Time-driven debouncing, based on elapsed wall-clock time instead of a
+//! fixed poll cadence.
+//!
+//! [`DebouncedInputPin`](crate::DebouncedInputPin) assumes `update()` is
+//! called on a steady ~1ms cadence, and counts polls to decide when a pin is
+//! debounced. [`TimedDebouncedInputPin`] instead takes the elapsed time since
+//! the last call and accumulates it against a configurable debounce
+//! `Duration`, only transitioning to `Active` once the pin has been
+//! continuously active for that long. This lets `update()` be driven from a
+//! main loop or any timer at an irregular cadence, while still debouncing for
+//! a deterministic, hardware-accurate interval.
+
+use core::marker::PhantomData;
+use core::time::Duration;
+
+use embedded_hal::digital::v2::InputPin;
+
+use crate::{ActiveHigh, ActiveLow, ActiveTrait, DebounceState};
+
+/// A debounced input pin driven by elapsed time rather than a poll counter.
+pub struct TimedDebouncedInputPin<T: InputPin, A> {
+    /// The wrapped pin.
+    pub pin: T,
+
+    /// Whether the pin is active-high or active-low.
+    activeness: PhantomData<A>,
+
+    /// Time the pin has been continuously active since the last reset.
+    elapsed: Duration,
+
+    /// How long the pin has to be continuously active to change it's
+    /// debounce state.
+    debounce_time: Duration,
+
+    /// The debounced pin state.
+    state: bool,
+}
+
+impl<T: InputPin, A> TimedDebouncedInputPin<T, A> {
+    /// Change the duration the pin has to be continuously active to change
+    /// it's debounce state.
+    pub fn set_debounce_time(&mut self, debounce_time: Duration) {
+        self.debounce_time = debounce_time;
+    }
+}
+
+impl<T: InputPin, A> InputPin for TimedDebouncedInputPin<T, A> {
+    type Error = T::Error;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        Ok(self.state)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        Ok(!self.state)
+    }
+}
+
+impl<T: InputPin> TimedDebouncedInputPin<T, ActiveHigh> {
+    /// Initializes a new `ActiveHigh` timed debounced input pin.
+    pub fn active_high(pin: T) -> Self {
+        Self {
+            pin,
+            activeness: PhantomData,
+            elapsed: Duration::from_millis(0),
+            debounce_time: Duration::from_millis(10),
+            state: false,
+        }
+    }
+
+    /// Updates the debounce logic, accounting for `elapsed` time since the
+    /// last call.
+    pub fn update(&mut self, elapsed: Duration) -> Result<DebounceState, T::Error> {
+        if self.pin.is_low()? {
+            self.elapsed = Duration::from_millis(0);
+            self.state = false;
+            Ok(DebounceState::Reset)
+        } else {
+            self.elapsed += elapsed;
+            if self.elapsed < self.debounce_time {
+                Ok(DebounceState::Debouncing)
+            } else {
+                self.state = true;
+                Ok(DebounceState::Active)
+            }
+        }
+    }
+}
+
+impl<T: InputPin> ActiveTrait for TimedDebouncedInputPin<T, ActiveHigh> {
+    type Error = T::Error;
+    fn is_active(&self) -> Result<bool, Self::Error> {
+        self.is_high()
+    }
+}
+
+impl<T: InputPin> TimedDebouncedInputPin<T, ActiveLow> {
+    /// Initializes a new `ActiveLow` timed debounced input pin.
+    pub fn active_low(pin: T) -> Self {
+        Self {
+            pin,
+            activeness: PhantomData,
+            elapsed: Duration::from_millis(0),
+            debounce_time: Duration::from_millis(10),
+            state: true,
+        }
+    }
+
+    /// Updates the debounce logic, accounting for `elapsed` time since the
+    /// last call.
+    pub fn update(&mut self, elapsed: Duration) -> Result<DebounceState, T::Error> {
+        if self.pin.is_high()? {
+            self.elapsed = Duration::from_millis(0);
+            self.state = true;
+            Ok(DebounceState::Reset)
+        } else {
+            self.elapsed += elapsed;
+            if self.elapsed < self.debounce_time {
+                Ok(DebounceState::Debouncing)
+            } else {
+                self.state = false;
+                Ok(DebounceState::Active)
+            }
+        }
+    }
+}
+
+impl<T: InputPin> ActiveTrait for TimedDebouncedInputPin<T, ActiveLow> {
+    type Error = T::Error;
+    fn is_active(&self) -> Result<bool, Self::Error> {
+        self.is_low()
+    }
+}